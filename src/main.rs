@@ -1,9 +1,6 @@
-use anyhow::{format_err, Error, Result};
+use anyhow::{format_err, Result};
 use serde::Serialize;
-use std::{
-    convert::{TryFrom, TryInto},
-    fs, io,
-};
+use std::{convert::TryInto, fs, io};
 use structopt::StructOpt;
 use ttf_parser::PlatformId;
 
@@ -71,10 +68,13 @@ impl Font {
         let font = ttf_parser::Font::from_data(input, index)
             .ok_or_else(|| format_err!("cannot parse font at index {}", index))?;
         Ok(Font {
-            names: font
-                .names()
-                .map(Name::try_from)
-                .collect::<Result<Vec<_>>>()?,
+            names: {
+                let ltags = font
+                    .table_data(ttf_parser::Tag::from_bytes(b"ltag"))
+                    .map(parse_ltag_table)
+                    .unwrap_or_default();
+                font.names().map(|n| Name::from_raw(n, &ltags)).collect()
+            },
             family_name: font.family_name(),
             post_script_name: font.post_script_name(),
             is_regular: font.is_regular(),
@@ -108,33 +108,104 @@ pub struct Name {
     name_id: NameId,
     name: String,
     platform_id: Option<String>,
-    language: &'static str,
+    language: Locale,
+    /// ISO-15924 script tag implied by the language id, e.g. `"Latn"` or `"Arab"`.
+    script: Option<&'static str>,
     encoding_id: u16,
     language_id: u16,
 }
 
-impl TryFrom<ttf_parser::Name<'_>> for Name {
-    type Error = Error;
-    fn try_from(name: ttf_parser::Name<'_>) -> Result<Self, Self::Error> {
-        Ok(Name {
+/// A decoded name-record locale, built from the platform-specific language id.
+///
+/// `language` and `region` are machine-readable (ISO-639 / ISO-3166-ish) codes for
+/// consumers that want to process the value programmatically. `description` carries
+/// the original human-readable string, where we have one, as a convenience.
+///
+/// AAT fonts can instead reference a BCP-47 tag directly via the `ltag` table; when that's
+/// how the locale was resolved, `tag` holds the full tag and `language`/`region` are left
+/// unset, since the tag already carries the complete information.
+#[derive(Serialize)]
+pub struct Locale {
+    language: Option<&'static str>,
+    region: Option<&'static str>,
+    description: Option<&'static str>,
+    tag: Option<String>,
+}
+
+impl Locale {
+    fn unknown() -> Self {
+        Locale {
+            language: None,
+            region: None,
+            description: None,
+            tag: None,
+        }
+    }
+}
+
+impl Name {
+    /// Build a `Name` from a raw `ttf_parser` name record. `ltags` is the font's parsed
+    /// `ltag` table (empty if it has none), used to resolve AAT language ids.
+    fn from_raw(name: ttf_parser::Name<'_>, ltags: &[String]) -> Self {
+        Name {
             platform_id: name.platform_id().map(|id| format!("{:?}", id)),
-            name: {
-                let name_bytes = name.name();
-                // rough hack
-                if name_bytes[0] == 0 && (name_bytes.len() % 2) == 0 {
-                    let iter = (0..name_bytes.len() / 2)
-                        .map(|i| u16::from_be_bytes([name_bytes[2 * i], name_bytes[2 * i + 1]]));
-                    std::char::decode_utf16(iter).collect::<Result<String, _>>()?
-                } else {
-                    String::from_utf8_lossy(name.name()).into_owned()
+            name: match name.platform_id() {
+                Some(PlatformId::Macintosh) => decode_mac_string(name.encoding_id(), name.name()),
+                Some(PlatformId::Windows) | Some(PlatformId::Unicode) => {
+                    decode_utf16be_lossy(name.name())
                 }
+                _ => String::from_utf8_lossy(name.name()).into_owned(),
             },
-            language: language(name.platform_id(), name.language_id()),
+            language: language(name.platform_id(), name.language_id(), ltags),
+            script: script(name.platform_id(), name.language_id()),
             name_id: NameId::from(name.name_id()),
             encoding_id: name.encoding_id(),
             language_id: name.language_id(),
-        })
+        }
+    }
+}
+
+/// Decode a big-endian UTF-16 byte string (as used by the Windows and Unicode name-table
+/// platforms), substituting U+FFFD for anything that doesn't form a valid code point rather
+/// than failing the whole record.
+fn decode_utf16be_lossy(bytes: &[u8]) -> String {
+    const REPLACEMENT: char = '\u{FFFD}';
+    let mut chunks = bytes.chunks_exact(2);
+    let has_trailing_byte = !chunks.remainder().is_empty();
+    let units: Vec<u16> = chunks
+        .map(|chunk| u16::from_be_bytes([chunk[0], chunk[1]]))
+        .collect();
+    let mut out = String::with_capacity(units.len());
+    let mut i = 0;
+    while i < units.len() {
+        let hi = units[i];
+        if (0xD800..=0xDBFF).contains(&hi) {
+            match units.get(i + 1) {
+                Some(&lo) if (0xDC00..=0xDFFF).contains(&lo) => {
+                    let c = 0x10000 + (((hi as u32 - 0xD800) << 10) + (lo as u32 - 0xDC00));
+                    out.push(char::from_u32(c).unwrap_or(REPLACEMENT));
+                    i += 2;
+                }
+                _ => {
+                    // Unpaired high surrogate. Don't consume whatever comes next — it may be
+                    // the start of its own valid pair — just flag this one and move on.
+                    out.push(REPLACEMENT);
+                    i += 1;
+                }
+            }
+        } else if (0xDC00..=0xDFFF).contains(&hi) {
+            // unpaired low surrogate
+            out.push(REPLACEMENT);
+            i += 1;
+        } else {
+            out.push(char::from_u32(hi as u32).unwrap_or(REPLACEMENT));
+            i += 1;
+        }
     }
+    if has_trailing_byte {
+        out.push(REPLACEMENT);
+    }
+    out
 }
 
 #[derive(Serialize)]
@@ -201,220 +272,606 @@ impl From<u16> for NameId {
     }
 }
 
-fn language(platform_id: Option<PlatformId>, language_id: u16) -> &'static str {
+fn language(platform_id: Option<PlatformId>, language_id: u16, ltags: &[String]) -> Locale {
+    // Apple platforms use `ltag` table indices, rather than a numeric id, once the id runs
+    // past the fixed Macintosh language-code space.
+    // https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6ltag.html
+    if platform_id == Some(PlatformId::Macintosh) && language_id >= 0x8000 {
+        if let Some(tag) = ltags.get((language_id - 0x8000) as usize) {
+            return Locale {
+                language: None,
+                region: None,
+                description: None,
+                tag: Some(tag.clone()),
+            };
+        }
+    }
     match platform_id {
-        // from https://docs.microsoft.com/en-us/typography/opentype/spec/name TODO add mac.
-        Some(PlatformId::Windows) => match language_id {
-            0 => "None",
-            0x0436 => "Afrikaans (South Africa)",
-            0x041C => "Albanian (Albania)",
-            0x0484 => "Alsatian (France)",
-            0x045E => "Amharic (Ethiopia)",
-            0x1401 => "Arabic (Algeria)",
-            0x3C01 => "Arabic (Bahrain)",
-            0x0C01 => "Arabic (Egypt)",
-            0x0801 => "Arabic (Iraq)",
-            0x2C01 => "Arabic (Jordan)",
-            0x3401 => "Arabic (Kuwait)",
-            0x3001 => "Arabic (Lebanon)",
-            0x1001 => "Arabic (Libya)",
-            0x1801 => "Arabic (Morocco)",
-            0x2001 => "Arabic (Oman)",
-            0x4001 => "Arabic (Qatar)",
-            0x0401 => "Arabic (Saudi Arabia)",
-            0x2801 => "Arabic (Syria)",
-            0x1C01 => "Arabic (Tunisia)",
-            0x3801 => "Arabic (U.A.E.)",
-            0x2401 => "Arabic (Yemen)",
-            0x042B => "Armenian (Armenia)",
-            0x044D => "Assamese (India)",
-            0x082C => "Azeri (Cyrillic) (Azerbaijan)",
-            0x042C => "Azeri (Latin) (Azerbaijan)",
-            0x046D => "Bashkir (Russia)",
-            0x042D => "Basque (Basque)",
-            0x0423 => "Belarusian (Belarus)",
-            0x0845 => "Bengali (Bangladesh)",
-            0x0445 => "Bengali (India)",
-            0x201A => "Bosnian (Cyrillic) (Bosnia and Herzegovina)",
-            0x141A => "Bosnian (Latin) (Bosnia and Herzegovina)",
-            0x047E => "Breton (France)",
-            0x0402 => "Bulgarian (Bulgaria)",
-            0x0403 => "Catalan (Catalan)",
-            0x0C04 => "Chinese (Hong Kong S.A.R.)",
-            0x1404 => "Chinese (Macao S.A.R.)",
-            0x0804 => "Chinese (People’s Republic of China)",
-            0x1004 => "Chinese (Singapore)",
-            0x0404 => "Chinese (Taiwan)",
-            0x0483 => "Corsican (France)",
-            0x041A => "Croatian (Croatia)",
-            0x101A => "Croatian (Latin) (Bosnia and Herzegovina)",
-            0x0405 => "Czech (Czech Republic)",
-            0x0406 => "Danish (Denmark)",
-            0x048C => "Dari (Afghanistan)",
-            0x0465 => "Divehi (Maldives)",
-            0x0813 => "Dutch (Belgium)",
-            0x0413 => "Dutch (Netherlands)",
-            0x0C09 => "English (Australia)",
-            0x2809 => "English (Belize)",
-            0x1009 => "English (Canada)",
-            0x2409 => "English (Caribbean)",
-            0x4009 => "English (India)",
-            0x1809 => "English (Ireland)",
-            0x2009 => "English (Jamaica)",
-            0x4409 => "English (Malaysia)",
-            0x1409 => "English (New Zealand)",
-            0x3409 => "English (Republic of the Philippines)",
-            0x4809 => "English (Singapore)",
-            0x1C09 => "English (South Africa)",
-            0x2C09 => "English (Trinidad and Tobago)",
-            0x0809 => "English (United Kingdom)",
-            0x0409 => "English (United States)",
-            0x3009 => "English (Zimbabwe)",
-            0x0425 => "Estonian (Estonia)",
-            0x0438 => "Faroese (Faroe Islands)",
-            0x0464 => "Filipino (Philippines)",
-            0x040B => "Finnish (Finland)",
-            0x080C => "French (Belgium)",
-            0x0C0C => "French (Canada)",
-            0x040C => "French (France)",
-            0x140c => "French (Luxembourg)",
-            0x180C => "French (Principality of Monaco)",
-            0x100C => "French (Switzerland)",
-            0x0462 => "Frisian (Netherlands)",
-            0x0456 => "Galician (Galician)",
-            0x0437 => "Georgian (Georgia)",
-            0x0C07 => "German (Austria)",
-            0x0407 => "German (Germany)",
-            0x1407 => "German (Liechtenstein)",
-            0x1007 => "German (Luxembourg)",
-            0x0807 => "German (Switzerland)",
-            0x0408 => "Greek (Greece)",
-            0x046F => "Greenlandic (Greenland)",
-            0x0447 => "Gujarati (India)",
-            0x0468 => "Hausa (Latin) (Nigeria)",
-            0x040D => "Hebrew (Israel)",
-            0x0439 => "Hindi (India)",
-            0x040E => "Hungarian (Hungary)",
-            0x040F => "Icelandic (Iceland)",
-            0x0470 => "Igbo (Nigeria)",
-            0x0421 => "Indonesian (Indonesia)",
-            0x045D => "Inuktitut (Canada)",
-            0x085D => "Inuktitut (Latin) (Canada)",
-            0x083C => "Irish (Ireland)",
-            0x0434 => "isiXhosa (South Africa)",
-            0x0435 => "isiZulu (South Africa)",
-            0x0410 => "Italian (Italy)",
-            0x0810 => "Italian (Switzerland)",
-            0x0411 => "Japanese (Japan)",
-            0x044B => "Kannada (India)",
-            0x043F => "Kazakh (Kazakhstan)",
-            0x0453 => "Khmer (Cambodia)",
-            0x0486 => "K’iche (Guatemala)",
-            0x0487 => "Kinyarwanda (Rwanda)",
-            0x0441 => "Kiswahili (Kenya)",
-            0x0457 => "Konkani (India)",
-            0x0412 => "Korean (Korea)",
-            0x0440 => "Kyrgyz (Kyrgyzstan)",
-            0x0454 => "Lao (Lao P.D.R.)",
-            0x0426 => "Latvian (Latvia)",
-            0x0427 => "Lithuanian (Lithuania)",
-            0x082E => "Lower Sorbian (Germany)",
-            0x046E => "Luxembourgish (Luxembourg)",
-            0x042F => "Macedonian (FYROM) (Former Yugoslav Republic of Macedonia)",
-            0x083E => "Malay (Brunei Darussalam)",
-            0x043E => "Malay (Malaysia)",
-            0x044C => "Malayalam (India)",
-            0x043A => "Maltese (Malta)",
-            0x0481 => "Maori (New Zealand)",
-            0x047A => "Mapudungun (Chile)",
-            0x044E => "Marathi (India)",
-            0x047C => "Mohawk (Mohawk)",
-            0x0450 => "Mongolian (Cyrillic) (Mongolia)",
-            0x0850 => "Mongolian (Traditional) (People’s Republic of China)",
-            0x0461 => "Nepali (Nepal)",
-            0x0414 => "Norwegian (Bokmal) (Norway)",
-            0x0814 => "Norwegian (Nynorsk) (Norway)",
-            0x0482 => "Occitan (France)",
-            0x0448 => "Odia (formerly Oriya) (India)",
-            0x0463 => "Pashto (Afghanistan)",
-            0x0415 => "Polish (Poland)",
-            0x0416 => "Portuguese (Brazil)",
-            0x0816 => "Portuguese (Portugal)",
-            0x0446 => "Punjabi (India)",
-            0x046B => "Quechua (Bolivia)",
-            0x086B => "Quechua (Ecuador)",
-            0x0C6B => "Quechua (Peru)",
-            0x0418 => "Romanian (Romania)",
-            0x0417 => "Romansh (Switzerland)",
-            0x0419 => "Russian (Russia)",
-            0x243B => "Sami (Inari) (Finland)",
-            0x103B => "Sami (Lule) (Norway)",
-            0x143B => "Sami (Lule) (Sweden)",
-            0x0C3B => "Sami (Northern) (Finland)",
-            0x043B => "Sami (Northern) (Norway)",
-            0x083B => "Sami (Northern) (Sweden)",
-            0x203B => "Sami (Skolt) (Finland)",
-            0x183B => "Sami (Southern) (Norway)",
-            0x1C3B => "Sami (Southern) (Sweden)",
-            0x044F => "Sanskrit (India)",
-            0x1C1A => "Serbian (Cyrillic) (Bosnia and Herzegovina)",
-            0x0C1A => "Serbian (Cyrillic) (Serbia)",
-            0x181A => "Serbian (Latin) (Bosnia and Herzegovina)",
-            0x081A => "Serbian (Latin) (Serbia)",
-            0x046C => "Sesotho sa Leboa (South Africa)",
-            0x0432 => "Setswana (South Africa)",
-            0x045B => "Sinhala (Sri Lanka)",
-            0x041B => "Slovak (Slovakia)",
-            0x0424 => "Slovenian (Slovenia)",
-            0x2C0A => "Spanish (Argentina)",
-            0x400A => "Spanish (Bolivia)",
-            0x340A => "Spanish (Chile)",
-            0x240A => "Spanish (Colombia)",
-            0x140A => "Spanish (Costa Rica)",
-            0x1C0A => "Spanish (Dominican Republic)",
-            0x300A => "Spanish (Ecuador)",
-            0x440A => "Spanish (El Salvador)",
-            0x100A => "Spanish (Guatemala)",
-            0x480A => "Spanish (Honduras)",
-            0x080A => "Spanish (Mexico)",
-            0x4C0A => "Spanish (Nicaragua)",
-            0x180A => "Spanish (Panama)",
-            0x3C0A => "Spanish (Paraguay)",
-            0x280A => "Spanish (Peru)",
-            0x500A => "Spanish (Puerto Rico)",
-            0x0C0A => "Spanish (Modern Sort) (Spain)",
-            0x040A => "Spanish (Traditional Sort) (Spain)",
-            0x540A => "Spanish (United States)",
-            0x380A => "Spanish (Uruguay)",
-            0x200A => "Spanish (Venezuela)",
-            0x081D => "Sweden (Finland)",
-            0x041D => "Swedish (Sweden)",
-            0x045A => "Syriac (Syria)",
-            0x0428 => "Tajik (Cyrillic) (Tajikistan)",
-            0x085F => "Tamazight (Latin) (Algeria)",
-            0x0449 => "Tamil (India)",
-            0x0444 => "Tatar (Russia)",
-            0x044A => "Telugu (India)",
-            0x041E => "Thai (Thailand)",
-            0x0451 => "Tibetan (PRC)",
-            0x041F => "Turkish (Turkey)",
-            0x0442 => "Turkmen (Turkmenistan)",
-            0x0480 => "Uighur (PRC)",
-            0x0422 => "Ukrainian (Ukraine)",
-            0x042E => "Upper Sorbian (Germany)",
-            0x0420 => "Urdu (Islamic Republic of Pakistan)",
-            0x0843 => "Uzbek (Cyrillic) (Uzbekistan)",
-            0x0443 => "Uzbek (Latin) (Uzbekistan)",
-            0x042A => "Vietnamese (Vietnam)",
-            0x0452 => "Welsh (United Kingdom)",
-            0x0488 => "Wolof (Senegal)",
-            0x0485 => "Yakut (Russia)",
-            0x0478 => "Yi (PRC)",
-            0x046A => "Yoruba (Nigeria)",
-            _ => "unknown",
-        },
-        _ => "unknown (todo)",
+        // from https://docs.microsoft.com/en-us/typography/opentype/spec/name
+        Some(PlatformId::Windows) => windows_locale(language_id),
+        Some(PlatformId::Macintosh) => mac_locale(language_id),
+        _ => Locale::unknown(),
+    }
+}
+
+/// Resolve the ISO-15924 script implied by a name record's language id, where the script
+/// isn't stored directly but is implied by the language (e.g. Arabic locales are written in
+/// the Arabic script). Returns `None` for unrecognised ids.
+fn script(platform_id: Option<PlatformId>, language_id: u16) -> Option<&'static str> {
+    match platform_id {
+        Some(PlatformId::Windows) => windows_script(language_id),
+        Some(PlatformId::Macintosh) => mac_script(language_id),
+        _ => None,
+    }
+}
+
+fn windows_script(language_id: u16) -> Option<&'static str> {
+    const ARABIC: &[u16] = &[
+        0x0401, 0x0801, 0x0C01, 0x1001, 0x1401, 0x1801, 0x1C01, 0x2001, 0x2401, 0x2801, 0x2C01,
+        0x3001, 0x3401, 0x3801, 0x3C01, 0x4001, 0x0429, 0x0420, 0x0463, 0x048C, 0x0480,
+    ];
+    const CYRILLIC: &[u16] = &[
+        0x0402, 0x0419, 0x0422, 0x0423, 0x0428, 0x0843, 0x082C, 0x0450, 0x1C1A, 0x0C1A, 0x201A,
+        0x043F, 0x0440, 0x042F, 0x0444, 0x046D, 0x0485,
+    ];
+    // Languages whose LCID implies a non-Latin, non-Arabic/Cyrillic/CJK script, so we don't
+    // fall through to the Latin default below.
+    const HEBREW: &[u16] = &[0x040D];
+    const GREEK: &[u16] = &[0x0408];
+    const THAI: &[u16] = &[0x041E];
+    const DEVANAGARI: &[u16] = &[0x0439, 0x044E, 0x0461, 0x044F, 0x0457];
+    const KHMER: &[u16] = &[0x0453];
+    const LAO: &[u16] = &[0x0454];
+    const SINHALA: &[u16] = &[0x045B];
+    const TIBETAN: &[u16] = &[0x0451];
+    const ETHIOPIC: &[u16] = &[0x045E];
+    const GEORGIAN: &[u16] = &[0x0437];
+    const TAMIL: &[u16] = &[0x0449];
+    const TELUGU: &[u16] = &[0x044A];
+    const KANNADA: &[u16] = &[0x044B];
+    const MALAYALAM: &[u16] = &[0x044C];
+    const GUJARATI: &[u16] = &[0x0447];
+    const GURMUKHI: &[u16] = &[0x0446];
+    const ORIYA: &[u16] = &[0x0448];
+    if language_id == 0 {
+        return None;
+    }
+    if ARABIC.contains(&language_id) {
+        return Some("Arab");
+    }
+    if language_id == 0x042B {
+        return Some("Armn");
+    }
+    if matches!(language_id, 0x0445 | 0x0845 | 0x044D) {
+        return Some("Beng");
+    }
+    if CYRILLIC.contains(&language_id) {
+        return Some("Cyrl");
+    }
+    match language_id {
+        0x0804 | 0x1004 => return Some("Hans"),
+        0x0404 | 0x0C04 | 0x1404 => return Some("Hant"),
+        0x0411 => return Some("Jpan"),
+        0x0412 => return Some("Kore"),
+        _ => {}
+    }
+    if HEBREW.contains(&language_id) {
+        return Some("Hebr");
+    }
+    if GREEK.contains(&language_id) {
+        return Some("Grek");
+    }
+    if THAI.contains(&language_id) {
+        return Some("Thai");
+    }
+    if DEVANAGARI.contains(&language_id) {
+        return Some("Deva");
+    }
+    if KHMER.contains(&language_id) {
+        return Some("Khmr");
+    }
+    if LAO.contains(&language_id) {
+        return Some("Laoo");
+    }
+    if SINHALA.contains(&language_id) {
+        return Some("Sinh");
+    }
+    if TIBETAN.contains(&language_id) {
+        return Some("Tibt");
+    }
+    if ETHIOPIC.contains(&language_id) {
+        return Some("Ethi");
+    }
+    if GEORGIAN.contains(&language_id) {
+        return Some("Geor");
+    }
+    if TAMIL.contains(&language_id) {
+        return Some("Taml");
+    }
+    if TELUGU.contains(&language_id) {
+        return Some("Telu");
+    }
+    if KANNADA.contains(&language_id) {
+        return Some("Knda");
+    }
+    if MALAYALAM.contains(&language_id) {
+        return Some("Mlym");
+    }
+    if GUJARATI.contains(&language_id) {
+        return Some("Gujr");
+    }
+    if GURMUKHI.contains(&language_id) {
+        return Some("Guru");
+    }
+    if ORIYA.contains(&language_id) {
+        return Some("Orya");
+    }
+    windows_locale(language_id).language.map(|_| "Latn")
+}
+
+fn mac_script(language_id: u16) -> Option<&'static str> {
+    const ARABIC: &[u16] = &[12, 20, 31, 59, 60];
+    const CYRILLIC: &[u16] = &[32, 42, 43, 44, 45, 46, 47, 48, 49, 54, 55, 58, 135];
+    // Non-Latin, non-Arabic/Cyrillic/CJK scripts among the classic Mac language ids.
+    const HEBREW: &[u16] = &[10];
+    const GREEK: &[u16] = &[14];
+    const THAI: &[u16] = &[22];
+    const DEVANAGARI: &[u16] = &[21, 65, 66];
+    const BENGALI: &[u16] = &[67, 68];
+    const GUJARATI: &[u16] = &[69];
+    const GURMUKHI: &[u16] = &[70];
+    const ORIYA: &[u16] = &[71];
+    const MALAYALAM: &[u16] = &[72];
+    const KANNADA: &[u16] = &[73];
+    const TAMIL: &[u16] = &[74];
+    const TELUGU: &[u16] = &[75];
+    const SINHALA: &[u16] = &[76];
+    const BURMESE: &[u16] = &[77];
+    const KHMER: &[u16] = &[78];
+    const LAO: &[u16] = &[79];
+    const TIBETAN: &[u16] = &[63];
+    const ETHIOPIC: &[u16] = &[85];
+    const GEORGIAN: &[u16] = &[52];
+    match language_id {
+        11 => Some("Jpan"),
+        23 => Some("Kore"),
+        19 => Some("Hant"),
+        33 => Some("Hans"),
+        51 => Some("Armn"),
+        _ if ARABIC.contains(&language_id) => Some("Arab"),
+        _ if CYRILLIC.contains(&language_id) => Some("Cyrl"),
+        _ if HEBREW.contains(&language_id) => Some("Hebr"),
+        _ if GREEK.contains(&language_id) => Some("Grek"),
+        _ if THAI.contains(&language_id) => Some("Thai"),
+        _ if DEVANAGARI.contains(&language_id) => Some("Deva"),
+        _ if BENGALI.contains(&language_id) => Some("Beng"),
+        _ if GUJARATI.contains(&language_id) => Some("Gujr"),
+        _ if GURMUKHI.contains(&language_id) => Some("Guru"),
+        _ if ORIYA.contains(&language_id) => Some("Orya"),
+        _ if MALAYALAM.contains(&language_id) => Some("Mlym"),
+        _ if KANNADA.contains(&language_id) => Some("Knda"),
+        _ if TAMIL.contains(&language_id) => Some("Taml"),
+        _ if TELUGU.contains(&language_id) => Some("Telu"),
+        _ if SINHALA.contains(&language_id) => Some("Sinh"),
+        _ if BURMESE.contains(&language_id) => Some("Mymr"),
+        _ if KHMER.contains(&language_id) => Some("Khmr"),
+        _ if LAO.contains(&language_id) => Some("Laoo"),
+        _ if TIBETAN.contains(&language_id) => Some("Tibt"),
+        _ if ETHIOPIC.contains(&language_id) => Some("Ethi"),
+        _ if GEORGIAN.contains(&language_id) => Some("Geor"),
+        _ => mac_locale(language_id).language.map(|_| "Latn"),
+    }
+}
+
+/// Look up a Windows LCID in the table from
+/// <https://docs.microsoft.com/en-us/typography/opentype/spec/name>, decoding it into an
+/// ISO-639 language code plus an optional ISO-3166 region code.
+fn windows_locale(language_id: u16) -> Locale {
+    let (language, region, description): (Option<&'static str>, Option<&'static str>, &'static str) =
+        match language_id {
+            0 => (None, None, "None"),
+            0x0436 => (Some("af"), Some("ZA"), "Afrikaans (South Africa)"),
+            0x041C => (Some("sq"), Some("AL"), "Albanian (Albania)"),
+            0x0484 => (Some("gsw"), Some("FR"), "Alsatian (France)"),
+            0x045E => (Some("am"), Some("ET"), "Amharic (Ethiopia)"),
+            0x1401 => (Some("ar"), Some("DZ"), "Arabic (Algeria)"),
+            0x3C01 => (Some("ar"), Some("BH"), "Arabic (Bahrain)"),
+            0x0C01 => (Some("ar"), Some("EG"), "Arabic (Egypt)"),
+            0x0801 => (Some("ar"), Some("IQ"), "Arabic (Iraq)"),
+            0x2C01 => (Some("ar"), Some("JO"), "Arabic (Jordan)"),
+            0x3401 => (Some("ar"), Some("KW"), "Arabic (Kuwait)"),
+            0x3001 => (Some("ar"), Some("LB"), "Arabic (Lebanon)"),
+            0x1001 => (Some("ar"), Some("LY"), "Arabic (Libya)"),
+            0x1801 => (Some("ar"), Some("MA"), "Arabic (Morocco)"),
+            0x2001 => (Some("ar"), Some("OM"), "Arabic (Oman)"),
+            0x4001 => (Some("ar"), Some("QA"), "Arabic (Qatar)"),
+            0x0401 => (Some("ar"), Some("SA"), "Arabic (Saudi Arabia)"),
+            0x2801 => (Some("ar"), Some("SY"), "Arabic (Syria)"),
+            0x1C01 => (Some("ar"), Some("TN"), "Arabic (Tunisia)"),
+            0x3801 => (Some("ar"), Some("AE"), "Arabic (U.A.E.)"),
+            0x2401 => (Some("ar"), Some("YE"), "Arabic (Yemen)"),
+            0x042B => (Some("hy"), Some("AM"), "Armenian (Armenia)"),
+            0x044D => (Some("as"), Some("IN"), "Assamese (India)"),
+            0x082C => (Some("az"), Some("AZ"), "Azeri (Cyrillic) (Azerbaijan)"),
+            0x042C => (Some("az"), Some("AZ"), "Azeri (Latin) (Azerbaijan)"),
+            0x046D => (Some("ba"), Some("RU"), "Bashkir (Russia)"),
+            0x042D => (Some("eu"), Some("ES"), "Basque (Basque)"),
+            0x0423 => (Some("be"), Some("BY"), "Belarusian (Belarus)"),
+            0x0845 => (Some("bn"), Some("BD"), "Bengali (Bangladesh)"),
+            0x0445 => (Some("bn"), Some("IN"), "Bengali (India)"),
+            0x201A => (Some("bs"), Some("BA"), "Bosnian (Cyrillic) (Bosnia and Herzegovina)"),
+            0x141A => (Some("bs"), Some("BA"), "Bosnian (Latin) (Bosnia and Herzegovina)"),
+            0x047E => (Some("br"), Some("FR"), "Breton (France)"),
+            0x0402 => (Some("bg"), Some("BG"), "Bulgarian (Bulgaria)"),
+            0x0403 => (Some("ca"), Some("ES"), "Catalan (Catalan)"),
+            0x0C04 => (Some("zh"), Some("HK"), "Chinese (Hong Kong S.A.R.)"),
+            0x1404 => (Some("zh"), Some("MO"), "Chinese (Macao S.A.R.)"),
+            0x0804 => (Some("zh"), Some("CN"), "Chinese (People’s Republic of China)"),
+            0x1004 => (Some("zh"), Some("SG"), "Chinese (Singapore)"),
+            0x0404 => (Some("zh"), Some("TW"), "Chinese (Taiwan)"),
+            0x0483 => (Some("co"), Some("FR"), "Corsican (France)"),
+            0x041A => (Some("hr"), Some("HR"), "Croatian (Croatia)"),
+            0x101A => (Some("hr"), Some("BA"), "Croatian (Latin) (Bosnia and Herzegovina)"),
+            0x0405 => (Some("cs"), Some("CZ"), "Czech (Czech Republic)"),
+            0x0406 => (Some("da"), Some("DK"), "Danish (Denmark)"),
+            0x048C => (Some("prs"), Some("AF"), "Dari (Afghanistan)"),
+            0x0465 => (Some("dv"), Some("MV"), "Divehi (Maldives)"),
+            0x0813 => (Some("nl"), Some("BE"), "Dutch (Belgium)"),
+            0x0413 => (Some("nl"), Some("NL"), "Dutch (Netherlands)"),
+            0x0C09 => (Some("en"), Some("AU"), "English (Australia)"),
+            0x2809 => (Some("en"), Some("BZ"), "English (Belize)"),
+            0x1009 => (Some("en"), Some("CA"), "English (Canada)"),
+            0x2409 => (Some("en"), None, "English (Caribbean)"),
+            0x4009 => (Some("en"), Some("IN"), "English (India)"),
+            0x1809 => (Some("en"), Some("IE"), "English (Ireland)"),
+            0x2009 => (Some("en"), Some("JM"), "English (Jamaica)"),
+            0x4409 => (Some("en"), Some("MY"), "English (Malaysia)"),
+            0x1409 => (Some("en"), Some("NZ"), "English (New Zealand)"),
+            0x3409 => (Some("en"), Some("PH"), "English (Republic of the Philippines)"),
+            0x4809 => (Some("en"), Some("SG"), "English (Singapore)"),
+            0x1C09 => (Some("en"), Some("ZA"), "English (South Africa)"),
+            0x2C09 => (Some("en"), Some("TT"), "English (Trinidad and Tobago)"),
+            0x0809 => (Some("en"), Some("GB"), "English (United Kingdom)"),
+            0x0409 => (Some("en"), Some("US"), "English (United States)"),
+            0x3009 => (Some("en"), Some("ZW"), "English (Zimbabwe)"),
+            0x0425 => (Some("et"), Some("EE"), "Estonian (Estonia)"),
+            0x0438 => (Some("fo"), Some("FO"), "Faroese (Faroe Islands)"),
+            0x0464 => (Some("fil"), Some("PH"), "Filipino (Philippines)"),
+            0x040B => (Some("fi"), Some("FI"), "Finnish (Finland)"),
+            0x080C => (Some("fr"), Some("BE"), "French (Belgium)"),
+            0x0C0C => (Some("fr"), Some("CA"), "French (Canada)"),
+            0x040C => (Some("fr"), Some("FR"), "French (France)"),
+            0x140c => (Some("fr"), Some("LU"), "French (Luxembourg)"),
+            0x180C => (Some("fr"), Some("MC"), "French (Principality of Monaco)"),
+            0x100C => (Some("fr"), Some("CH"), "French (Switzerland)"),
+            0x0462 => (Some("fy"), Some("NL"), "Frisian (Netherlands)"),
+            0x0456 => (Some("gl"), Some("ES"), "Galician (Galician)"),
+            0x0437 => (Some("ka"), Some("GE"), "Georgian (Georgia)"),
+            0x0C07 => (Some("de"), Some("AT"), "German (Austria)"),
+            0x0407 => (Some("de"), Some("DE"), "German (Germany)"),
+            0x1407 => (Some("de"), Some("LI"), "German (Liechtenstein)"),
+            0x1007 => (Some("de"), Some("LU"), "German (Luxembourg)"),
+            0x0807 => (Some("de"), Some("CH"), "German (Switzerland)"),
+            0x0408 => (Some("el"), Some("GR"), "Greek (Greece)"),
+            0x046F => (Some("kl"), Some("GL"), "Greenlandic (Greenland)"),
+            0x0447 => (Some("gu"), Some("IN"), "Gujarati (India)"),
+            0x0468 => (Some("ha"), Some("NG"), "Hausa (Latin) (Nigeria)"),
+            0x040D => (Some("he"), Some("IL"), "Hebrew (Israel)"),
+            0x0439 => (Some("hi"), Some("IN"), "Hindi (India)"),
+            0x040E => (Some("hu"), Some("HU"), "Hungarian (Hungary)"),
+            0x040F => (Some("is"), Some("IS"), "Icelandic (Iceland)"),
+            0x0470 => (Some("ig"), Some("NG"), "Igbo (Nigeria)"),
+            0x0421 => (Some("id"), Some("ID"), "Indonesian (Indonesia)"),
+            0x045D => (Some("iu"), Some("CA"), "Inuktitut (Canada)"),
+            0x085D => (Some("iu"), Some("CA"), "Inuktitut (Latin) (Canada)"),
+            0x083C => (Some("ga"), Some("IE"), "Irish (Ireland)"),
+            0x0434 => (Some("xh"), Some("ZA"), "isiXhosa (South Africa)"),
+            0x0435 => (Some("zu"), Some("ZA"), "isiZulu (South Africa)"),
+            0x0410 => (Some("it"), Some("IT"), "Italian (Italy)"),
+            0x0810 => (Some("it"), Some("CH"), "Italian (Switzerland)"),
+            0x0411 => (Some("ja"), Some("JP"), "Japanese (Japan)"),
+            0x044B => (Some("kn"), Some("IN"), "Kannada (India)"),
+            0x043F => (Some("kk"), Some("KZ"), "Kazakh (Kazakhstan)"),
+            0x0453 => (Some("km"), Some("KH"), "Khmer (Cambodia)"),
+            0x0486 => (Some("quc"), Some("GT"), "K’iche (Guatemala)"),
+            0x0487 => (Some("rw"), Some("RW"), "Kinyarwanda (Rwanda)"),
+            0x0441 => (Some("sw"), Some("KE"), "Kiswahili (Kenya)"),
+            0x0457 => (Some("kok"), Some("IN"), "Konkani (India)"),
+            0x0412 => (Some("ko"), Some("KR"), "Korean (Korea)"),
+            0x0440 => (Some("ky"), Some("KG"), "Kyrgyz (Kyrgyzstan)"),
+            0x0454 => (Some("lo"), Some("LA"), "Lao (Lao P.D.R.)"),
+            0x0426 => (Some("lv"), Some("LV"), "Latvian (Latvia)"),
+            0x0427 => (Some("lt"), Some("LT"), "Lithuanian (Lithuania)"),
+            0x082E => (Some("dsb"), Some("DE"), "Lower Sorbian (Germany)"),
+            0x046E => (Some("lb"), Some("LU"), "Luxembourgish (Luxembourg)"),
+            0x042F => (Some("mk"), Some("MK"), "Macedonian (FYROM) (Former Yugoslav Republic of Macedonia)"),
+            0x083E => (Some("ms"), Some("BN"), "Malay (Brunei Darussalam)"),
+            0x043E => (Some("ms"), Some("MY"), "Malay (Malaysia)"),
+            0x044C => (Some("ml"), Some("IN"), "Malayalam (India)"),
+            0x043A => (Some("mt"), Some("MT"), "Maltese (Malta)"),
+            0x0481 => (Some("mi"), Some("NZ"), "Maori (New Zealand)"),
+            0x047A => (Some("arn"), Some("CL"), "Mapudungun (Chile)"),
+            0x044E => (Some("mr"), Some("IN"), "Marathi (India)"),
+            0x047C => (Some("moh"), None, "Mohawk (Mohawk)"),
+            0x0450 => (Some("mn"), Some("MN"), "Mongolian (Cyrillic) (Mongolia)"),
+            0x0850 => (Some("mn"), Some("CN"), "Mongolian (Traditional) (People’s Republic of China)"),
+            0x0461 => (Some("ne"), Some("NP"), "Nepali (Nepal)"),
+            0x0414 => (Some("nb"), Some("NO"), "Norwegian (Bokmal) (Norway)"),
+            0x0814 => (Some("nn"), Some("NO"), "Norwegian (Nynorsk) (Norway)"),
+            0x0482 => (Some("oc"), Some("FR"), "Occitan (France)"),
+            0x0448 => (Some("or"), Some("IN"), "Odia (formerly Oriya) (India)"),
+            0x0463 => (Some("ps"), Some("AF"), "Pashto (Afghanistan)"),
+            0x0429 => (Some("fa"), Some("IR"), "Persian (Iran)"),
+            0x0415 => (Some("pl"), Some("PL"), "Polish (Poland)"),
+            0x0416 => (Some("pt"), Some("BR"), "Portuguese (Brazil)"),
+            0x0816 => (Some("pt"), Some("PT"), "Portuguese (Portugal)"),
+            0x0446 => (Some("pa"), Some("IN"), "Punjabi (India)"),
+            0x046B => (Some("qu"), Some("BO"), "Quechua (Bolivia)"),
+            0x086B => (Some("qu"), Some("EC"), "Quechua (Ecuador)"),
+            0x0C6B => (Some("qu"), Some("PE"), "Quechua (Peru)"),
+            0x0418 => (Some("ro"), Some("RO"), "Romanian (Romania)"),
+            0x0417 => (Some("rm"), Some("CH"), "Romansh (Switzerland)"),
+            0x0419 => (Some("ru"), Some("RU"), "Russian (Russia)"),
+            0x243B => (Some("smn"), Some("FI"), "Sami (Inari) (Finland)"),
+            0x103B => (Some("smj"), Some("NO"), "Sami (Lule) (Norway)"),
+            0x143B => (Some("smj"), Some("SE"), "Sami (Lule) (Sweden)"),
+            0x0C3B => (Some("se"), Some("FI"), "Sami (Northern) (Finland)"),
+            0x043B => (Some("se"), Some("NO"), "Sami (Northern) (Norway)"),
+            0x083B => (Some("se"), Some("SE"), "Sami (Northern) (Sweden)"),
+            0x203B => (Some("sms"), Some("FI"), "Sami (Skolt) (Finland)"),
+            0x183B => (Some("sma"), Some("NO"), "Sami (Southern) (Norway)"),
+            0x1C3B => (Some("sma"), Some("SE"), "Sami (Southern) (Sweden)"),
+            0x044F => (Some("sa"), Some("IN"), "Sanskrit (India)"),
+            0x1C1A => (Some("sr"), Some("BA"), "Serbian (Cyrillic) (Bosnia and Herzegovina)"),
+            0x0C1A => (Some("sr"), Some("RS"), "Serbian (Cyrillic) (Serbia)"),
+            0x181A => (Some("sr"), Some("BA"), "Serbian (Latin) (Bosnia and Herzegovina)"),
+            0x081A => (Some("sr"), Some("RS"), "Serbian (Latin) (Serbia)"),
+            0x046C => (Some("nso"), Some("ZA"), "Sesotho sa Leboa (South Africa)"),
+            0x0432 => (Some("tn"), Some("ZA"), "Setswana (South Africa)"),
+            0x045B => (Some("si"), Some("LK"), "Sinhala (Sri Lanka)"),
+            0x041B => (Some("sk"), Some("SK"), "Slovak (Slovakia)"),
+            0x0424 => (Some("sl"), Some("SI"), "Slovenian (Slovenia)"),
+            0x2C0A => (Some("es"), Some("AR"), "Spanish (Argentina)"),
+            0x400A => (Some("es"), Some("BO"), "Spanish (Bolivia)"),
+            0x340A => (Some("es"), Some("CL"), "Spanish (Chile)"),
+            0x240A => (Some("es"), Some("CO"), "Spanish (Colombia)"),
+            0x140A => (Some("es"), Some("CR"), "Spanish (Costa Rica)"),
+            0x1C0A => (Some("es"), Some("DO"), "Spanish (Dominican Republic)"),
+            0x300A => (Some("es"), Some("EC"), "Spanish (Ecuador)"),
+            0x440A => (Some("es"), Some("SV"), "Spanish (El Salvador)"),
+            0x100A => (Some("es"), Some("GT"), "Spanish (Guatemala)"),
+            0x480A => (Some("es"), Some("HN"), "Spanish (Honduras)"),
+            0x080A => (Some("es"), Some("MX"), "Spanish (Mexico)"),
+            0x4C0A => (Some("es"), Some("NI"), "Spanish (Nicaragua)"),
+            0x180A => (Some("es"), Some("PA"), "Spanish (Panama)"),
+            0x3C0A => (Some("es"), Some("PY"), "Spanish (Paraguay)"),
+            0x280A => (Some("es"), Some("PE"), "Spanish (Peru)"),
+            0x500A => (Some("es"), Some("PR"), "Spanish (Puerto Rico)"),
+            0x0C0A => (Some("es"), Some("ES"), "Spanish (Modern Sort) (Spain)"),
+            0x040A => (Some("es"), Some("ES"), "Spanish (Traditional Sort) (Spain)"),
+            0x540A => (Some("es"), Some("US"), "Spanish (United States)"),
+            0x380A => (Some("es"), Some("UY"), "Spanish (Uruguay)"),
+            0x200A => (Some("es"), Some("VE"), "Spanish (Venezuela)"),
+            0x081D => (Some("sv"), Some("FI"), "Sweden (Finland)"),
+            0x041D => (Some("sv"), Some("SE"), "Swedish (Sweden)"),
+            0x045A => (Some("syr"), Some("SY"), "Syriac (Syria)"),
+            0x0428 => (Some("tg"), Some("TJ"), "Tajik (Cyrillic) (Tajikistan)"),
+            0x085F => (Some("tzm"), Some("DZ"), "Tamazight (Latin) (Algeria)"),
+            0x0449 => (Some("ta"), Some("IN"), "Tamil (India)"),
+            0x0444 => (Some("tt"), Some("RU"), "Tatar (Russia)"),
+            0x044A => (Some("te"), Some("IN"), "Telugu (India)"),
+            0x041E => (Some("th"), Some("TH"), "Thai (Thailand)"),
+            0x0451 => (Some("bo"), Some("CN"), "Tibetan (PRC)"),
+            0x041F => (Some("tr"), Some("TR"), "Turkish (Turkey)"),
+            0x0442 => (Some("tk"), Some("TM"), "Turkmen (Turkmenistan)"),
+            0x0480 => (Some("ug"), Some("CN"), "Uighur (PRC)"),
+            0x0422 => (Some("uk"), Some("UA"), "Ukrainian (Ukraine)"),
+            0x042E => (Some("hsb"), Some("DE"), "Upper Sorbian (Germany)"),
+            0x0420 => (Some("ur"), Some("PK"), "Urdu (Islamic Republic of Pakistan)"),
+            0x0843 => (Some("uz"), Some("UZ"), "Uzbek (Cyrillic) (Uzbekistan)"),
+            0x0443 => (Some("uz"), Some("UZ"), "Uzbek (Latin) (Uzbekistan)"),
+            0x042A => (Some("vi"), Some("VN"), "Vietnamese (Vietnam)"),
+            0x0452 => (Some("cy"), Some("GB"), "Welsh (United Kingdom)"),
+            0x0488 => (Some("wo"), Some("SN"), "Wolof (Senegal)"),
+            0x0485 => (Some("sah"), Some("RU"), "Yakut (Russia)"),
+            0x0478 => (Some("ii"), Some("CN"), "Yi (PRC)"),
+            0x046A => (Some("yo"), Some("NG"), "Yoruba (Nigeria)"),
+            _ => return Locale::unknown(),
+        };
+    Locale {
+        language,
+        region,
+        description: Some(description),
+        tag: None,
+    }
+}
+
+/// Look up a classic Macintosh language id (see the "Language code" table in Apple's
+/// TrueType Reference Manual) and decode it into an ISO-639 language code plus an
+/// optional ISO-3166 region code.
+fn mac_locale(language_id: u16) -> Locale {
+    let (language, region, description): (Option<&'static str>, Option<&'static str>, &'static str) =
+        match language_id {
+            0 => (Some("en"), None, "English"),
+            1 => (Some("fr"), None, "French"),
+            2 => (Some("de"), None, "German"),
+            3 => (Some("it"), None, "Italian"),
+            4 => (Some("nl"), None, "Dutch"),
+            5 => (Some("sv"), None, "Swedish"),
+            6 => (Some("es"), None, "Spanish"),
+            7 => (Some("da"), None, "Danish"),
+            8 => (Some("pt"), None, "Portuguese"),
+            9 => (Some("nb"), None, "Norwegian"),
+            10 => (Some("he"), None, "Hebrew"),
+            11 => (Some("ja"), None, "Japanese"),
+            12 => (Some("ar"), None, "Arabic"),
+            13 => (Some("fi"), None, "Finnish"),
+            14 => (Some("el"), None, "Greek"),
+            15 => (Some("is"), None, "Icelandic"),
+            16 => (Some("mt"), None, "Maltese"),
+            17 => (Some("tr"), None, "Turkish"),
+            18 => (Some("hr"), None, "Croatian"),
+            19 => (Some("zh"), Some("TW"), "Chinese (Traditional)"),
+            20 => (Some("ur"), None, "Urdu"),
+            21 => (Some("hi"), None, "Hindi"),
+            22 => (Some("th"), None, "Thai"),
+            23 => (Some("ko"), None, "Korean"),
+            24 => (Some("lt"), None, "Lithuanian"),
+            25 => (Some("pl"), None, "Polish"),
+            26 => (Some("hu"), None, "Hungarian"),
+            27 => (Some("et"), None, "Estonian"),
+            28 => (Some("lv"), None, "Latvian"),
+            29 => (Some("se"), None, "Sami"),
+            30 => (Some("fo"), None, "Faroese"),
+            31 => (Some("fa"), None, "Farsi"),
+            32 => (Some("ru"), None, "Russian"),
+            33 => (Some("zh"), Some("CN"), "Chinese (Simplified)"),
+            34 => (Some("nl"), Some("BE"), "Flemish"),
+            35 => (Some("ga"), None, "Irish Gaelic"),
+            36 => (Some("sq"), None, "Albanian"),
+            37 => (Some("ro"), None, "Romanian"),
+            38 => (Some("cs"), None, "Czech"),
+            39 => (Some("sk"), None, "Slovak"),
+            40 => (Some("sl"), None, "Slovenian"),
+            41 => (Some("yi"), None, "Yiddish"),
+            42 => (Some("sr"), None, "Serbian"),
+            43 => (Some("mk"), None, "Macedonian"),
+            44 => (Some("bg"), None, "Bulgarian"),
+            45 => (Some("uk"), None, "Ukrainian"),
+            46 => (Some("be"), None, "Byelorussian"),
+            47 => (Some("uz"), None, "Uzbek"),
+            48 => (Some("kk"), None, "Kazakh"),
+            49 => (Some("az"), None, "Azerbaijani (Cyrillic)"),
+            50 => (Some("az"), None, "Azerbaijani (Arabic)"),
+            51 => (Some("hy"), None, "Armenian"),
+            52 => (Some("ka"), None, "Georgian"),
+            53 => (Some("ro"), Some("MD"), "Moldavian"),
+            54 => (Some("ky"), None, "Kirghiz"),
+            55 => (Some("tg"), None, "Tajiki"),
+            56 => (Some("tk"), None, "Turkmen"),
+            57 => (Some("mn"), None, "Mongolian"),
+            58 => (Some("mn"), None, "Mongolian (Cyrillic)"),
+            59 => (Some("ps"), None, "Pashto"),
+            60 => (Some("ku"), None, "Kurdish"),
+            61 => (Some("ks"), None, "Kashmiri"),
+            62 => (Some("sd"), None, "Sindhi"),
+            63 => (Some("bo"), None, "Tibetan"),
+            64 => (Some("ne"), None, "Nepali"),
+            65 => (Some("sa"), None, "Sanskrit"),
+            66 => (Some("mr"), None, "Marathi"),
+            67 => (Some("bn"), None, "Bengali"),
+            68 => (Some("as"), None, "Assamese"),
+            69 => (Some("gu"), None, "Gujarati"),
+            70 => (Some("pa"), None, "Punjabi"),
+            71 => (Some("or"), None, "Oriya"),
+            72 => (Some("ml"), None, "Malayalam"),
+            73 => (Some("kn"), None, "Kannada"),
+            74 => (Some("ta"), None, "Tamil"),
+            75 => (Some("te"), None, "Telugu"),
+            76 => (Some("si"), None, "Sinhalese"),
+            77 => (Some("my"), None, "Burmese"),
+            78 => (Some("km"), None, "Khmer"),
+            79 => (Some("lo"), None, "Lao"),
+            80 => (Some("vi"), None, "Vietnamese"),
+            81 => (Some("id"), None, "Indonesian"),
+            82 => (Some("tl"), None, "Tagalog"),
+            83 => (Some("ms"), None, "Malay (Roman)"),
+            84 => (Some("ms"), None, "Malay (Arabic)"),
+            85 => (Some("am"), None, "Amharic"),
+            86 => (Some("ti"), None, "Tigrinya"),
+            87 => (Some("om"), None, "Oromo"),
+            88 => (Some("so"), None, "Somali"),
+            89 => (Some("sw"), None, "Swahili"),
+            90 => (Some("rw"), None, "Kinyarwanda"),
+            91 => (Some("rn"), None, "Rundi"),
+            92 => (Some("ny"), None, "Nyanja/Chewa"),
+            93 => (Some("mg"), None, "Malagasy"),
+            94 => (Some("eo"), None, "Esperanto"),
+            128 => (Some("cy"), None, "Welsh"),
+            129 => (Some("eu"), None, "Basque"),
+            130 => (Some("ca"), None, "Catalan"),
+            131 => (Some("la"), None, "Latin"),
+            132 => (Some("qu"), None, "Quechua"),
+            133 => (Some("gn"), None, "Guarani"),
+            134 => (Some("ay"), None, "Aymara"),
+            135 => (Some("tt"), None, "Tatar"),
+            136 => (Some("ug"), None, "Uighur"),
+            137 => (Some("dz"), None, "Dzongkha"),
+            138 => (Some("jv"), None, "Javanese (Roman)"),
+            139 => (Some("su"), None, "Sundanese (Roman)"),
+            140 => (Some("gl"), None, "Galician"),
+            141 => (Some("af"), None, "Afrikaans"),
+            142 => (Some("br"), None, "Breton"),
+            143 => (Some("iu"), None, "Inuktitut"),
+            144 => (Some("gd"), None, "Scottish Gaelic"),
+            145 => (Some("gv"), None, "Manx Gaelic"),
+            146 => (Some("ga"), None, "Irish Gaelic (with dot above)"),
+            147 => (Some("to"), None, "Tongan"),
+            149 => (Some("kl"), None, "Greenlandic"),
+            150 => (Some("az"), None, "Azerbaijani (Roman)"),
+            _ => return Locale::unknown(),
+        };
+    Locale {
+        language,
+        region,
+        description: Some(description),
+        tag: None,
+    }
+}
+
+/// Parse an AAT `ltag` table into its list of BCP-47 tag strings, indexed by
+/// `language_id - 0x8000` for the name records that reference it.
+/// https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6ltag.html
+fn parse_ltag_table(data: &[u8]) -> Vec<String> {
+    let num_tags = match data.get(8..12) {
+        Some(bytes) => u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize,
+        None => return Vec::new(),
+    };
+    // `num_tags` comes straight from the font file; cap it at the number of records the
+    // table could actually hold so a crafted huge value can't turn this into a long spin
+    // of no-op lookups.
+    let num_tags = num_tags.min(data.len().saturating_sub(12) / 4);
+    (0..num_tags)
+        .filter_map(|i| {
+            let record = data.get(12 + i * 4..12 + i * 4 + 4)?;
+            let offset = u16::from_be_bytes([record[0], record[1]]) as usize;
+            let length = u16::from_be_bytes([record[2], record[3]]) as usize;
+            let bytes = data.get(offset..offset + length)?;
+            std::str::from_utf8(bytes).ok().map(str::to_owned)
+        })
+        .collect()
+}
+
+/// Decode a byte string from a Macintosh-platform name record.
+///
+/// `encoding_id` 0 is Macintosh Roman, the common case for legacy fonts; other Mac
+/// script/encoding ids are rarer and decoded as lossy UTF-8 rather than getting their own
+/// conversion table.
+fn decode_mac_string(encoding_id: u16, bytes: &[u8]) -> String {
+    if encoding_id == 0 {
+        bytes.iter().map(|&b| mac_roman_to_char(b)).collect()
+    } else {
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+}
+
+/// Map a single Macintosh Roman byte to its Unicode codepoint. Bytes below 0x80 are ASCII;
+/// bytes 0x80-0xFF are the accented/symbol block laid out in Apple's Mac OS Roman table.
+fn mac_roman_to_char(byte: u8) -> char {
+    if byte < 0x80 {
+        return byte as char;
     }
+    const HIGH: [char; 128] = [
+        'Ä', 'Å', 'Ç', 'É', 'Ñ', 'Ö', 'Ü', 'á', 'à', 'â', 'ä', 'ã', 'å', 'ç', 'é', 'è', 'ê', 'ë',
+        'í', 'ì', 'î', 'ï', 'ñ', 'ó', 'ò', 'ô', 'ö', 'õ', 'ú', 'ù', 'û', 'ü', '†', '°', '¢', '£',
+        '§', '•', '¶', 'ß', '®', '©', '™', '´', '¨', '≠', 'Æ', 'Ø', '∞', '±', '≤', '≥', '¥', 'µ',
+        '∂', '∑', '∏', 'π', '∫', 'ª', 'º', 'Ω', 'æ', 'ø', '¿', '¡', '¬', '√', 'ƒ', '≈', '∆', '«',
+        '»', '…', '\u{00A0}', 'À', 'Ã', 'Õ', 'Œ', 'œ', '–', '—', '“', '”', '‘', '’', '÷', '◊',
+        'ÿ', 'Ÿ', '⁄', '€', '‹', '›', 'ﬁ', 'ﬂ', '‡', '·', '‚', '„', '‰', 'Â', 'Ê', 'Á', 'Ë', 'È',
+        'Í', 'Î', 'Ï', 'Ì', 'Ó', 'Ô', '\u{F8FF}', 'Ò', 'Ú', 'Û', 'Ù', 'ı', 'ˆ', '˜', '¯', '˘', '˙',
+        '˚', '¸', '˝', '˛', 'ˇ',
+    ];
+    HIGH[(byte - 0x80) as usize]
 }
 
 #[derive(Serialize)]